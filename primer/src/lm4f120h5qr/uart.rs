@@ -6,11 +6,14 @@
 //
 // ****************************************************************************
 
-use core::intrinsics::{volatile_store, volatile_load};
-use core::ptr::Unique;
+use core::marker::PhantomData;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use embedded_serial;
 use super::registers;
 use super::gpio;
 
+use self::reg::{RW, UartRegisters};
+
 // ****************************************************************************
 //
 // Public Types
@@ -30,12 +33,101 @@ pub enum UartId {
     Uart7,
 }
 
+/// The number of data bits per frame
+#[derive(PartialEq, Clone, Copy)]
+pub enum DataBits {
+    Five,
+    Six,
+    Seven,
+    Eight,
+}
+
+/// The parity bit mode
+#[derive(PartialEq, Clone, Copy)]
+pub enum Parity {
+    None,
+    Even,
+    Odd,
+}
+
+/// The number of stop bits per frame
+#[derive(PartialEq, Clone, Copy)]
+pub enum StopBits {
+    One,
+    Two,
+}
+
+/// Describes the wire format of a UART frame. The `default` is 8/N/1, which
+/// reproduces the behaviour this module used to hard-code.
+#[derive(PartialEq, Clone, Copy)]
+pub struct UartConfig {
+    pub data_bits: DataBits,
+    pub parity: Parity,
+    pub stop_bits: StopBits,
+}
+
+impl Default for UartConfig {
+    fn default() -> UartConfig {
+        UartConfig {
+            data_bits: DataBits::Eight,
+            parity: Parity::None,
+            stop_bits: StopBits::One,
+        }
+    }
+}
+
+/// Describes the clocks feeding the UART. Carries the UART peripheral clock
+/// in Hz, from which the baud-rate divisors are derived. The `default`
+/// reproduces the 66 MHz this module used to hard-code.
+#[derive(PartialEq, Clone, Copy)]
+pub struct ClockConfig {
+    pub uart_clock: u32,
+}
+
+impl ClockConfig {
+    /// Build a `ClockConfig` from the UART peripheral clock, in Hz.
+    pub fn new(uart_clock: u32) -> ClockConfig {
+        ClockConfig { uart_clock: uart_clock }
+    }
+}
+
+impl Default for ClockConfig {
+    fn default() -> ClockConfig {
+        ClockConfig { uart_clock: 66000000 }
+    }
+}
+
+/// The error type returned by the non-blocking serial traits. There is only
+/// one failure mode on this hardware: the FIFO was full (on transmit) or
+/// empty (on receive), so the operation would have blocked.
+#[derive(PartialEq, Clone, Copy)]
+pub enum Error {
+    /// The operation could not complete without blocking.
+    WouldBlock,
+}
+
 /// Controls a single UART
-/// Only supports 8/N/1 - who needs anything else?
 pub struct Uart {
     id: UartId,
     baud: u32,
-    reg: Unique<registers::UartRegisters>,
+    config: UartConfig,
+    clocks: ClockConfig,
+    reg: &'static UartRegisters,
+}
+
+/// An interrupt-driven UART with background TX and RX buffering.
+///
+/// The hardware FIFOs are only a few bytes deep, so bytes arriving while the
+/// CPU is busy elsewhere (e.g. inside `kinetis::delay`) are otherwise lost.
+/// `BufferedUart` owns a transmit and a receive ring buffer and uses the
+/// UART interrupt to move bytes between the FIFOs and the rings in the
+/// background: `read` only takes bytes from the RX ring, and `write` queues
+/// into the TX ring and then, with interrupts masked, moves queued bytes into
+/// the hardware FIFO and re-arms the TX interrupt.
+pub struct BufferedUart<'a> {
+    uart: Uart,
+    tx: RingBuffer<'a>,
+    rx: RingBuffer<'a>,
 }
 
 // ****************************************************************************
@@ -44,7 +136,255 @@ pub struct Uart {
 //
 // ****************************************************************************
 
-// None
+/// The typed register layer for the UART peripheral.
+///
+/// Each MMIO register is a volatile cell (`RO`/`WO`/`RW`); control registers
+/// with named bit-fields get a newtype generated by the `register!` macro
+/// exposing `.read()`, `.write(|w| ...)` and `.modify(|r, w| ...)` with
+/// chainable boolean accessors, so the init sequence reads as
+/// `ctl.modify(|_, w| w.rxe(true).txe(true).uarten(true))` rather than as
+/// hand-rolled pointer arithmetic.
+mod reg {
+    // This module defines the complete RO/WO/RW register vocabulary; not every
+    // cell kind or accessor is exercised by the current driver.
+    #![allow(dead_code)]
+
+    use core::cell::UnsafeCell;
+    use core::ptr::{read_volatile, write_volatile};
+
+    /// A read-only volatile register.
+    pub struct RO<T: Copy> {
+        value: UnsafeCell<T>,
+    }
+
+    /// A write-only volatile register.
+    pub struct WO<T: Copy> {
+        value: UnsafeCell<T>,
+    }
+
+    /// A read-write volatile register.
+    pub struct RW<T: Copy> {
+        value: UnsafeCell<T>,
+    }
+
+    impl<T: Copy> RO<T> {
+        pub fn read(&self) -> T {
+            unsafe { read_volatile(self.value.get()) }
+        }
+    }
+
+    impl<T: Copy> WO<T> {
+        pub fn write(&self, value: T) {
+            unsafe { write_volatile(self.value.get(), value) }
+        }
+    }
+
+    impl<T: Copy> RW<T> {
+        pub fn read(&self) -> T {
+            unsafe { read_volatile(self.value.get()) }
+        }
+
+        pub fn write(&self, value: T) {
+            unsafe { write_volatile(self.value.get(), value) }
+        }
+    }
+
+    /// Generates a bit-field register newtype with `read`/`write`/`modify`
+    /// and one chainable boolean accessor per named field.
+    macro_rules! register {
+        ($(#[$meta:meta])* $name:ident { $( $field:ident : $bit:expr ),* $(,)* }) => {
+            $(#[$meta])*
+            pub mod $name {
+                use core::cell::UnsafeCell;
+                use core::ptr::{read_volatile, write_volatile};
+
+                /// The volatile register cell.
+                pub struct Register {
+                    value: UnsafeCell<u32>,
+                }
+
+                /// A snapshot of the register, as read from hardware.
+                pub struct R {
+                    bits: u32,
+                }
+
+                /// A value being assembled for writing back to hardware.
+                pub struct W {
+                    bits: u32,
+                }
+
+                impl Register {
+                    pub fn read(&self) -> R {
+                        R { bits: unsafe { read_volatile(self.value.get()) } }
+                    }
+
+                    pub fn write<F>(&self, f: F)
+                        where F: FnOnce(&mut W) -> &mut W
+                    {
+                        let mut w = W { bits: 0 };
+                        f(&mut w);
+                        unsafe { write_volatile(self.value.get(), w.bits) }
+                    }
+
+                    pub fn modify<F>(&self, f: F)
+                        where F: FnOnce(&R, &mut W) -> &mut W
+                    {
+                        let bits = unsafe { read_volatile(self.value.get()) };
+                        let r = R { bits: bits };
+                        let mut w = W { bits: bits };
+                        f(&r, &mut w);
+                        unsafe { write_volatile(self.value.get(), w.bits) }
+                    }
+                }
+
+                impl R {
+                    /// The raw bits currently in the register.
+                    #[allow(dead_code)]
+                    pub fn bits(&self) -> u32 {
+                        self.bits
+                    }
+                    $(
+                        pub fn $field(&self) -> bool {
+                            (self.bits & (1 << $bit)) != 0
+                        }
+                    )*
+                }
+
+                impl W {
+                    /// Overwrite the whole register with raw bits.
+                    #[allow(dead_code)]
+                    pub fn bits(&mut self, bits: u32) -> &mut W {
+                        self.bits = bits;
+                        self
+                    }
+                    $(
+                        pub fn $field(&mut self, value: bool) -> &mut W {
+                            if value {
+                                self.bits |= 1 << $bit;
+                            } else {
+                                self.bits &= !(1 << $bit);
+                            }
+                            self
+                        }
+                    )*
+                }
+            }
+        };
+    }
+
+    register!(
+        /// UART Control register (`UARTCTL`).
+        ctl { uarten: 0, txe: 8, rxe: 9 }
+    );
+
+    register!(
+        /// UART Line Control register (`UARTLCRH`). Its fields are written as
+        /// a computed value, so only the raw `bits` accessor is used.
+        lcrh {}
+    );
+
+    register!(
+        /// UART Flag register (`UARTFR`).
+        fr { rxfe: 4, txff: 5 }
+    );
+
+    register!(
+        /// UART Interrupt Mask register (`UARTIM`).
+        im { rxim: 4, txim: 5 }
+    );
+
+    /// The UART register block, laid out at the peripheral's MMIO offsets.
+    #[repr(C)]
+    pub struct UartRegisters {
+        pub dr: RW<u32>, // 0x000 Data
+        _reserved0: [u32; 5], // 0x004 .. 0x018
+        pub fr: fr::Register, // 0x018 Flag
+        _reserved1: [u32; 2], // 0x01C .. 0x024
+        pub ibrd: RW<u32>, // 0x024 Integer baud-rate divisor
+        pub fbrd: RW<u32>, // 0x028 Fractional baud-rate divisor
+        pub lcrh: lcrh::Register, // 0x02C Line control
+        pub ctl: ctl::Register, // 0x030 Control
+        _reserved2: u32, // 0x034 Interrupt FIFO level select
+        pub im: im::Register, // 0x038 Interrupt mask
+    }
+}
+
+/// A lock-free single-producer/single-consumer ring buffer over a fixed
+/// backing slice. One byte is always left unused so that a full buffer is
+/// distinguishable from an empty one.
+///
+/// The producer owns `end` and the consumer owns `start`; neither index is
+/// ever written by the other side. The data bytes, however, are mutated
+/// through a shared `&self` from both sides, so the backing storage is held
+/// as a raw `*mut u8` (the provenance `&[u8]` would not carry write
+/// permission) with a `PhantomData` borrow to keep the slice alive.
+struct RingBuffer<'a> {
+    buffer: *mut u8,
+    len: usize,
+    start: AtomicUsize,
+    end: AtomicUsize,
+    _borrow: PhantomData<&'a mut [u8]>,
+}
+
+impl<'a> RingBuffer<'a> {
+    fn new(buffer: &'a mut [u8]) -> RingBuffer<'a> {
+        RingBuffer {
+            len: buffer.len(),
+            buffer: buffer.as_mut_ptr(),
+            start: AtomicUsize::new(0),
+            end: AtomicUsize::new(0),
+            _borrow: PhantomData,
+        }
+    }
+
+    fn wrap(&self, index: usize) -> usize {
+        index % self.len
+    }
+
+    fn is_empty(&self) -> bool {
+        self.start.load(Ordering::Acquire) == self.end.load(Ordering::Acquire)
+    }
+
+    fn is_full(&self) -> bool {
+        let end = self.end.load(Ordering::Acquire);
+        self.wrap(end + 1) == self.start.load(Ordering::Acquire)
+    }
+
+    /// Producer side: store a byte at `end` then publish the advanced index.
+    /// Returns `false` (dropping the byte) if the buffer is full.
+    ///
+    /// The index is published with `Release` so the data-byte store is ordered
+    /// before it: on the TX ring the ISR can preempt the producer mid-`push`,
+    /// and the paired `Acquire` load in `pop` guarantees it never observes the
+    /// advanced index before the byte has landed.
+    fn push(&self, byte: u8) -> bool {
+        let end = self.end.load(Ordering::Relaxed);
+        let next = self.wrap(end + 1);
+        if next == self.start.load(Ordering::Acquire) {
+            return false;
+        }
+        unsafe {
+            *self.buffer.offset(end as isize) = byte;
+        }
+        self.end.store(next, Ordering::Release);
+        true
+    }
+
+    /// Consumer side: read the byte at `start` then publish the advanced
+    /// index. Returns `None` if the buffer is empty.
+    ///
+    /// The `Acquire` load of `end` pairs with the producer's `Release` store so
+    /// the byte read below is guaranteed to be the one the producer wrote.
+    fn pop(&self) -> Option<u8> {
+        let start = self.start.load(Ordering::Relaxed);
+        if start == self.end.load(Ordering::Acquire) {
+            return None;
+        }
+        let byte = unsafe { *self.buffer.offset(start as isize) };
+        self.start.store(self.wrap(start + 1), Ordering::Release);
+        Some(byte)
+    }
+}
 
 // ****************************************************************************
 //
@@ -61,10 +401,12 @@ pub struct Uart {
 // ****************************************************************************
 
 impl Uart {
-    pub fn new(id: UartId, baud: u32) -> Uart {
+    pub fn new(id: UartId, baud: u32, config: UartConfig, clocks: ClockConfig) -> Uart {
         let mut uart = Uart {
             id: id,
             baud: baud,
+            config: config,
+            clocks: clocks,
             reg: get_uart_registers(id),
         };
         uart.init();
@@ -74,56 +416,105 @@ impl Uart {
     pub fn init(&mut self) -> () {
         // Do GPIO pin muxing
         gpio::enable_uart(self.id);
-        // Enable UART module in RCGUART register p306
-        unsafe {
-            let mut reg: usize = volatile_load(registers::SYSCTL_RCGCUART_R);
-            reg |= match self.id {
-                UartId::Uart0 => 1 << 0,
-                UartId::Uart1 => 1 << 1,
-                UartId::Uart2 => 1 << 2,
-                UartId::Uart3 => 1 << 3,
-                UartId::Uart4 => 1 << 4,
-                UartId::Uart5 => 1 << 5,
-                UartId::Uart6 => 1 << 6,
-                UartId::Uart7 => 1 << 7,
-            };
-            volatile_store(registers::SYSCTL_RCGCUART_R, reg);
-        }
+        // Enable UART module in RCGCUART register p306
+        let rcgc = unsafe { &*(registers::SYSCTL_RCGCUART_R as *const RW<u32>) };
+        let enable_bit: u32 = match self.id {
+            UartId::Uart0 => 1 << 0,
+            UartId::Uart1 => 1 << 1,
+            UartId::Uart2 => 1 << 2,
+            UartId::Uart3 => 1 << 3,
+            UartId::Uart4 => 1 << 4,
+            UartId::Uart5 => 1 << 5,
+            UartId::Uart6 => 1 << 6,
+            UartId::Uart7 => 1 << 7,
+        };
+        rcgc.write(rcgc.read() | enable_bit);
         // Disable UART and all features
-        unsafe {
-            self.reg.get_mut().ctl = 0;
+        self.reg.ctl.write(|w| w.bits(0));
+        // Store the upper and lower parts of the baud-rate divider
+        let baud_int = self.baud_divisor();
+        self.reg.ibrd.write(baud_int / 64);
+        self.reg.fbrd.write(baud_int % 64);
+        // Calculate the UART Line Control register value from the frame format
+        let mut lcrh: u32 = match self.config.data_bits {
+            DataBits::Five => registers::UART_LCRH_WLEN_5,
+            DataBits::Six => registers::UART_LCRH_WLEN_6,
+            DataBits::Seven => registers::UART_LCRH_WLEN_7,
+            DataBits::Eight => registers::UART_LCRH_WLEN_8,
+        };
+        match self.config.parity {
+            Parity::None => {}
+            Parity::Even => lcrh |= registers::UART_LCRH_PEN | registers::UART_LCRH_EPS,
+            Parity::Odd => lcrh |= registers::UART_LCRH_PEN,
         }
-        // Calculate the baud rate values
-        unsafe {
-            // baud_div = CLOCK_RATE / (16 * baud_rate);
-            // baud_int = round(baud_div * 64)
-            let baud_int: u32 = (((66000000 * 8) / self.baud) + 1) / 2;
-            // Store the upper and lower parts of the divider
-            self.reg.get_mut().ibrd = (baud_int / 64) as usize;
-            self.reg.get_mut().fbrd = (baud_int % 64) as usize;
-        }
-        // Calculate the UART Line Control register value
-        unsafe {
-            // 8N1
-            self.reg.get_mut().lcrh = registers::UART_LCRH_WLEN_8;
+        if self.config.stop_bits == StopBits::Two {
+            lcrh |= registers::UART_LCRH_STP2;
         }
+        self.reg.lcrh.write(|w| w.bits(lcrh));
         // Clear the flags
-        unsafe {
-            self.reg.get_mut().rf = 0;
-        }
+        self.reg.fr.write(|w| w.bits(0));
         // Enable
-        unsafe {
-            self.reg.get_mut().ctl = registers::UART_CTL_RXE | registers::UART_CTL_TXE |
-                                     registers::UART_CTL_UARTEN;
+        self.reg.ctl.modify(|_, w| w.rxe(true).txe(true).uarten(true));
+    }
+
+    /// The baud-rate divider in 64ths, i.e. `ibrd * 64 + fbrd`.
+    ///
+    /// `baud_div = clk / (16 * baud)`, with the fractional part rounded to the
+    /// nearest 64th: `round(baud_div * 64) = ((clk * 8 / baud) + 1) / 2`.
+    fn baud_divisor(&self) -> u32 {
+        if self.baud == 0 {
+            return 0;
+        }
+        (((self.clocks.uart_clock * 8) / self.baud) + 1) / 2
+    }
+
+    /// Reports the baud rate the hardware actually produces with the current
+    /// clock and requested rate, together with the error from the requested
+    /// rate in hundredths of a percent (e.g. `150` means +1.5%). A large
+    /// magnitude means the requested rate is not achievable from this clock.
+    ///
+    /// When the requested rate is too high for the clock the divisor rounds to
+    /// zero and no achievable rate exists; this is reported as `(0, i32::MAX)`
+    /// rather than faulting, so callers can detect it at setup time.
+    pub fn effective_baud(&self) -> (u32, i32) {
+        // actual = clk / (16 * (divisor / 64)) = clk * 4 / divisor
+        let divisor = self.baud_divisor();
+        if divisor == 0 {
+            return (0, ::core::i32::MAX);
         }
+        let actual = (self.clocks.uart_clock * 4) / divisor;
+        let requested = self.baud as i64;
+        let error = ((actual as i64 - requested) * 10000) / requested;
+        (actual, error as i32)
     }
 
     fn putc(&mut self, value: u8) {
-        unsafe {
-            while (self.reg.get_mut().rf & registers::UART_FR_TXFF) != 0 {
+        while self.reg.fr.read().txff() {
+            unsafe {
+                asm!("NOP");
+            }
+        }
+        self.reg.dr.write(value as u32);
+    }
+
+    /// Read a single octet from the receive FIFO, if one is waiting.
+    fn try_read(&mut self) -> Result<u8, Error> {
+        if self.reg.fr.read().rxfe() {
+            Err(Error::WouldBlock)
+        } else {
+            Ok((self.reg.dr.read() & 0xFF) as u8)
+        }
+    }
+
+    /// Read a single octet, spinning until one arrives.
+    fn read(&mut self) -> u8 {
+        loop {
+            if let Ok(value) = self.try_read() {
+                return value;
+            }
+            unsafe {
                 asm!("NOP");
             }
-            self.reg.get_mut().data = value as usize;
         }
     }
 }
@@ -137,23 +528,160 @@ impl ::core::fmt::Write for Uart {
     }
 }
 
+impl embedded_serial::NonBlockingTx for Uart {
+    type Error = Error;
+
+    /// Write a single octet, unless the transmit FIFO is full.
+    fn putc_try(&mut self, ch: u8) -> Result<(), Error> {
+        if self.reg.fr.read().txff() {
+            Err(Error::WouldBlock)
+        } else {
+            self.reg.dr.write(ch as u32);
+            Ok(())
+        }
+    }
+}
+
+impl embedded_serial::BlockingTx for Uart {
+    type Error = Error;
+
+    /// Write a single octet, spinning until the transmit FIFO has room.
+    fn putc(&mut self, ch: u8) -> Result<(), Error> {
+        Uart::putc(self, ch);
+        Ok(())
+    }
+}
+
+impl embedded_serial::NonBlockingRx for Uart {
+    type Error = Error;
+
+    /// Read a single octet, unless the receive FIFO is empty.
+    fn getc_try(&mut self) -> Result<u8, Error> {
+        self.try_read()
+    }
+}
+
+impl embedded_serial::BlockingRx for Uart {
+    type Error = Error;
+
+    /// Read a single octet, spinning until one arrives.
+    fn getc(&mut self) -> Result<u8, Error> {
+        Ok(self.read())
+    }
+}
+
+impl<'a> BufferedUart<'a> {
+    /// Wrap an already-initialised `Uart` with the supplied TX and RX backing
+    /// buffers and enable its receive and transmit interrupts.
+    pub fn new(uart: Uart,
+               tx_buffer: &'a mut [u8],
+               rx_buffer: &'a mut [u8])
+               -> BufferedUart<'a> {
+        uart.reg.im.modify(|_, w| w.rxim(true).txim(true));
+        BufferedUart {
+            uart: uart,
+            tx: RingBuffer::new(tx_buffer),
+            rx: RingBuffer::new(rx_buffer),
+        }
+    }
+
+    /// Queue a byte for transmission. Returns `false` if the TX ring is full.
+    ///
+    /// Primes the hardware directly and re-arms the TX interrupt, all with the
+    /// UART IRQ masked. The PL011 TX interrupt is edge-triggered on a FIFO
+    /// level *transition*, so merely unmasking it while the holding register is
+    /// already empty would never assert it and the byte would sit in the ring
+    /// forever. We therefore pop into `dr` ourselves, but doing so from thread
+    /// context would add a second consumer to the single-consumer TX ring and
+    /// a second writer to `im`; masking interrupts keeps the ISR from
+    /// preempting us mid-sequence so both stay strictly single-accessor.
+    pub fn write(&mut self, byte: u8) -> bool {
+        let queued = self.tx.push(byte);
+        if queued {
+            self.critical(|me| {
+                // Refill the hardware from the ring up to `txff`; the falling
+                // FIFO level is what kicks off a self-sustaining TX drain.
+                while !me.uart.reg.fr.read().txff() {
+                    match me.tx.pop() {
+                        Some(byte) => me.uart.reg.dr.write(byte as u32),
+                        None => break,
+                    }
+                }
+                me.uart.reg.im.modify(|_, w| w.txim(true));
+            });
+        }
+        queued
+    }
+
+    /// Run `body` with interrupts globally masked, restoring the previous
+    /// `PRIMASK` afterward. This is the module's critical section: it stops the
+    /// UART ISR from preempting a ring or `im` update performed in thread
+    /// context, so the rings keep their single-producer/single-consumer
+    /// invariant and the `im` read-modify-write does not race the ISR's own.
+    fn critical<F, R>(&mut self, body: F) -> R
+        where F: FnOnce(&mut Self) -> R
+    {
+        let primask: u32;
+        unsafe {
+            asm!("mrs $0, PRIMASK" : "=r"(primask) : : "memory" : "volatile");
+            asm!("cpsid i" : : : "memory" : "volatile");
+        }
+        let result = body(self);
+        if primask & 1 == 0 {
+            unsafe {
+                asm!("cpsie i" : : : "memory" : "volatile");
+            }
+        }
+        result
+    }
+
+    /// Take the next received byte from the RX ring, or `None` if empty.
+    pub fn read(&mut self) -> Option<u8> {
+        self.rx.pop()
+    }
+
+    /// Service the UART interrupt: drain the hardware RX FIFO into the RX ring
+    /// and refill the hardware TX FIFO from the TX ring. Call this from the
+    /// UART interrupt handler.
+    pub fn interrupt(&mut self) {
+        // RX producer: drain the hardware FIFO until it reports empty.
+        while !self.uart.reg.fr.read().rxfe() {
+            let byte = (self.uart.reg.dr.read() & 0xFF) as u8;
+            self.rx.push(byte);
+        }
+        // TX consumer: refill the hardware FIFO from the ring.
+        while !self.uart.reg.fr.read().txff() {
+            match self.tx.pop() {
+                Some(byte) => self.uart.reg.dr.write(byte as u32),
+                None => break,
+            }
+        }
+        // Mask off the TX interrupt once the ring has drained, otherwise an
+        // empty-FIFO TX interrupt would fire continuously.
+        if self.tx.is_empty() {
+            self.uart.reg.im.modify(|_, w| w.txim(false));
+        }
+    }
+}
+
 // ****************************************************************************
 //
 // Private Functions
 //
 // ****************************************************************************
 
-fn get_uart_registers(uart: UartId) -> Unique<registers::UartRegisters> {
-    match uart {
-        UartId::Uart0 => unsafe { Unique::new(registers::UART0_DR_R as *mut _) },
-        UartId::Uart1 => unsafe { Unique::new(registers::UART1_DR_R as *mut _) },
-        UartId::Uart2 => unsafe { Unique::new(registers::UART2_DR_R as *mut _) },
-        UartId::Uart3 => unsafe { Unique::new(registers::UART3_DR_R as *mut _) },
-        UartId::Uart4 => unsafe { Unique::new(registers::UART4_DR_R as *mut _) },
-        UartId::Uart5 => unsafe { Unique::new(registers::UART5_DR_R as *mut _) },
-        UartId::Uart6 => unsafe { Unique::new(registers::UART6_DR_R as *mut _) },
-        UartId::Uart7 => unsafe { Unique::new(registers::UART7_DR_R as *mut _) },
-    }
+fn get_uart_registers(uart: UartId) -> &'static UartRegisters {
+    let base = match uart {
+        UartId::Uart0 => registers::UART0_DR_R,
+        UartId::Uart1 => registers::UART1_DR_R,
+        UartId::Uart2 => registers::UART2_DR_R,
+        UartId::Uart3 => registers::UART3_DR_R,
+        UartId::Uart4 => registers::UART4_DR_R,
+        UartId::Uart5 => registers::UART5_DR_R,
+        UartId::Uart6 => registers::UART6_DR_R,
+        UartId::Uart7 => registers::UART7_DR_R,
+    };
+    unsafe { &*(base as *const UartRegisters) }
 }
 
 // ****************************************************************************